@@ -103,12 +103,177 @@ pub enum Outcome<S, E, F> {
     Forward(F),
 }
 
-/// Conversion trait from some type into an Outcome type.
+/// Unwraps an [Outcome](/rocket/outcome/enum.Outcome.html) to its `Success` value or
+/// propagates a `Failure`/`Forward` by returning from the enclosing function.
+///
+/// This macro is to `Outcome` what the `?` operator is to `Result`: it lets
+/// `FromRequest` and `FromData` implementations chain several fallible guard
+/// steps linearly instead of matching and re-wrapping each inner `Outcome` by
+/// hand. Given an expression that evaluates to an `Outcome<S, E, F>`, it yields
+/// the `S` of a `Success` inline, and on a `Failure(e)`/`Forward(f)` returns
+/// `Outcome::Failure(e.into())`/`Outcome::Forward(f.into())` from the caller.
+///
+/// Pair it with [IntoOutcome](/rocket/outcome/trait.IntoOutcome.html) to feed a `Result` or
+/// `Option` into the macro:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # use rocket::outcome::{Outcome, IntoOutcome};
+/// # use rocket::outcome::Outcome::*;
+/// fn guard(input: Result<i32, &'static str>) -> Outcome<i32, &'static str, ()> {
+///     let value = try_outcome!(input.into_outcome(()));
+///     Success(value + 1)
+/// }
+///
+/// # fn main() {
+/// assert_eq!(guard(Ok(10)), Success(11));
+/// assert_eq!(guard(Err("bad")), Failure("bad"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_outcome {
+    ($expr:expr) => (match $expr {
+        $crate::outcome::Outcome::Success(val) => val,
+        $crate::outcome::Outcome::Failure(e) => {
+            return $crate::outcome::Outcome::Failure(::std::convert::From::from(e))
+        },
+        $crate::outcome::Outcome::Forward(f) => {
+            return $crate::outcome::Outcome::Forward(::std::convert::From::from(f))
+        },
+    });
+}
+
+/// Conversion trait from some type into an `Outcome` type.
+///
+/// Both `Result<S, E>` and `Option<S>` implement this trait so that standard
+/// two-variant types can be lifted into a three-variant `Outcome` by supplying
+/// the value to use for the missing variant.
+/// [into_outcome](/rocket/outcome/trait.IntoOutcome.html#tymethod.into_outcome)
+/// turns the "absent" case into a `Failure`, while
+/// [or_forward](/rocket/outcome/trait.IntoOutcome.html#tymethod.or_forward) turns
+/// it into a `Forward`.
 pub trait IntoOutcome<S, E, F> {
-    fn into_outcome(self) -> Outcome<S, E, F>;
+    /// The type of the supplied value used to build a `Failure`.
+    type Failure: Sized;
+
+    /// The type of the supplied value used to build a `Forward`.
+    type Forward: Sized;
+
+    /// Converts `self` into an `Outcome`, using `failure` for the `Failure`
+    /// value if `self` represents an absent success. For a `Result`, `failure`
+    /// is `()` and is not used: an `Err(e)` becomes `Failure(e)`.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::{Outcome, IntoOutcome};
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Result<i32, &str> = Ok(10);
+    /// let o: Outcome<i32, &str, usize> = x.into_outcome(());
+    /// assert_eq!(o, Success(10));
+    ///
+    /// let x: Option<i32> = None;
+    /// let o: Outcome<i32, &str, usize> = x.into_outcome("absent");
+    /// assert_eq!(o, Failure("absent"));
+    /// ```
+    fn into_outcome(self, failure: Self::Failure) -> Outcome<S, E, F>;
+
+    /// Converts `self` into an `Outcome`, using `forward` for the `Forward`
+    /// value if `self` represents an absent success.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::{Outcome, IntoOutcome};
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Option<i32> = Some(10);
+    /// let o: Outcome<i32, &str, usize> = x.or_forward(25);
+    /// assert_eq!(o, Success(10));
+    ///
+    /// let x: Option<i32> = None;
+    /// let o: Outcome<i32, &str, usize> = x.or_forward(25);
+    /// assert_eq!(o, Forward(25));
+    /// ```
+    fn or_forward(self, forward: Self::Forward) -> Outcome<S, E, F>;
+}
+
+impl<S, E, F> IntoOutcome<S, E, F> for Result<S, E> {
+    type Failure = ();
+    type Forward = F;
+
+    #[inline]
+    fn into_outcome(self, _: ()) -> Outcome<S, E, F> {
+        match self {
+            Ok(val) => Success(val),
+            Err(err) => Failure(err),
+        }
+    }
+
+    #[inline]
+    fn or_forward(self, forward: F) -> Outcome<S, E, F> {
+        match self {
+            Ok(val) => Success(val),
+            Err(_) => Forward(forward),
+        }
+    }
+}
+
+impl<S, E, F> IntoOutcome<S, E, F> for Option<S> {
+    type Failure = E;
+    type Forward = F;
+
+    #[inline]
+    fn into_outcome(self, failure: E) -> Outcome<S, E, F> {
+        match self {
+            Some(val) => Success(val),
+            None => Failure(failure),
+        }
+    }
+
+    #[inline]
+    fn or_forward(self, forward: F) -> Outcome<S, E, F> {
+        match self {
+            Some(val) => Success(val),
+            None => Forward(forward),
+        }
+    }
 }
 
 impl<S, E, F> Outcome<S, E, F> {
+    /// Builds an `Outcome` from a `Result<S, E>`, mapping `Ok(v)` to
+    /// `Success(v)` and `Err(e)` to `Failure(e)`.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Outcome::from_result(Ok(10));
+    /// assert_eq!(x, Success(10));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Outcome::from_result(Err("nope"));
+    /// assert_eq!(x, Failure("nope"));
+    /// ```
+    #[inline]
+    pub fn from_result(result: Result<S, E>) -> Outcome<S, E, F> {
+        result.into_outcome(())
+    }
+
+    /// Builds an `Outcome` from an `Option<S>`, mapping `Some(v)` to
+    /// `Success(v)` and `None` to `Forward(forward)`.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Outcome::from_option(Some(10), 25);
+    /// assert_eq!(x, Success(10));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Outcome::from_option(None, 25);
+    /// assert_eq!(x, Forward(25));
+    /// ```
+    #[inline]
+    pub fn from_option(option: Option<S>, forward: F) -> Outcome<S, E, F> {
+        option.or_forward(forward)
+    }
+
     /// Unwraps the Outcome, yielding the contents of a Success.
     ///
     /// # Panics
@@ -308,6 +473,60 @@ impl<S, E, F> Outcome<S, E, F> {
         }
     }
 
+    /// Converts from `Outcome<S, E, F>` to `Result<S, Outcome<S, E, F>>`.
+    ///
+    /// Returns `Ok` of the `Success` value if this is a `Success`. Otherwise,
+    /// returns `Err` of the unchanged `Outcome` (a `Failure` or `Forward`) so
+    /// that a caller can early-return it as-is.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.ok_or_forward(), Ok(10));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Forward(25);
+    /// assert_eq!(x.ok_or_forward(), Err(Forward(25)));
+    /// ```
+    #[inline]
+    pub fn ok_or_forward(self) -> Result<S, Outcome<S, E, F>> {
+        match self {
+            Success(val) => Ok(val),
+            Failure(val) => Err(Failure(val)),
+            Forward(val) => Err(Forward(val)),
+        }
+    }
+
+    /// Converts from `Outcome<S, E, F>` to `Result<S, E>`, collapsing a
+    /// `Forward` into the failure branch via `on_forward`.
+    ///
+    /// Returns `Ok` of the `Success` value, `Err` of the `Failure` value, or,
+    /// for a `Forward`, `Err` of the value produced by calling `on_forward`
+    /// with the forwarded value.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.into_result(|_| "forwarded"), Ok(10));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Failure("error");
+    /// assert_eq!(x.into_result(|_| "forwarded"), Err("error"));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Forward(25);
+    /// assert_eq!(x.into_result(|_| "forwarded"), Err("forwarded"));
+    /// ```
+    #[inline]
+    pub fn into_result<M: FnOnce(F) -> E>(self, on_forward: M) -> Result<S, E> {
+        match self {
+            Success(val) => Ok(val),
+            Failure(val) => Err(val),
+            Forward(val) => Err(on_forward(val)),
+        }
+    }
+
     /// Converts from `Outcome<S, E, F>` to `Outcome<&S, &E, &F>`.
     ///
     /// ```rust
@@ -351,6 +570,112 @@ impl<S, E, F> Outcome<S, E, F> {
         }
     }
 
+    /// Maps an `Outcome<S, E, F>` to an `Outcome<T, E, F>` by applying the
+    /// function `f` to a contained `Success` value, leaving a `Failure` or
+    /// `Forward` value untouched.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.map(|v| v + 1), Success(11));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Failure("Hi! I'm an error.");
+    /// assert_eq!(x.map(|v| v + 1), Failure("Hi! I'm an error."));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Forward(25);
+    /// assert_eq!(x.map(|v| v + 1), Forward(25));
+    /// ```
+    #[inline]
+    pub fn map<T, M: FnOnce(S) -> T>(self, f: M) -> Outcome<T, E, F> {
+        match self {
+            Success(val) => Success(f(val)),
+            Failure(val) => Failure(val),
+            Forward(val) => Forward(val),
+        }
+    }
+
+    /// Maps an `Outcome<S, E, F>` to an `Outcome<S, T, F>` by applying the
+    /// function `f` to a contained `Failure` value, leaving a `Success` or
+    /// `Forward` value untouched.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.map_failure(|_| 5), Success(10));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Failure("Hi! I'm an error.");
+    /// assert_eq!(x.map_failure(|_| 5), Failure(5));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Forward(25);
+    /// assert_eq!(x.map_failure(|_| 5), Forward(25));
+    /// ```
+    #[inline]
+    pub fn map_failure<T, M: FnOnce(E) -> T>(self, f: M) -> Outcome<S, T, F> {
+        match self {
+            Success(val) => Success(val),
+            Failure(val) => Failure(f(val)),
+            Forward(val) => Forward(val),
+        }
+    }
+
+    /// Maps an `Outcome<S, E, F>` to an `Outcome<S, E, T>` by applying the
+    /// function `f` to a contained `Forward` value, leaving a `Success` or
+    /// `Failure` value untouched.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.map_forward(|v| v + 1), Success(10));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Failure("Hi! I'm an error.");
+    /// assert_eq!(x.map_forward(|v| v + 1), Failure("Hi! I'm an error."));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Forward(25);
+    /// assert_eq!(x.map_forward(|v| v + 1), Forward(26));
+    /// ```
+    #[inline]
+    pub fn map_forward<T, M: FnOnce(F) -> T>(self, f: M) -> Outcome<S, E, T> {
+        match self {
+            Success(val) => Success(val),
+            Failure(val) => Failure(val),
+            Forward(val) => Forward(f(val)),
+        }
+    }
+
+    /// Calls `f` with the contained `Success` value and returns the resulting
+    /// `Outcome`, short-circuiting on a `Failure` or `Forward` value.
+    ///
+    /// ```rust
+    /// # use rocket::outcome::Outcome;
+    /// # use rocket::outcome::Outcome::*;
+    /// #
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.and_then(|v| Success(v + 1)), Success(11));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Success(10);
+    /// assert_eq!(x.and_then(|_| Failure::<i32, _, _>("boom")), Failure("boom"));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Failure("Hi! I'm an error.");
+    /// assert_eq!(x.and_then(|v| Success(v + 1)), Failure("Hi! I'm an error."));
+    ///
+    /// let x: Outcome<i32, &str, usize> = Forward(25);
+    /// assert_eq!(x.and_then(|v| Success(v + 1)), Forward(25));
+    /// ```
+    #[inline]
+    pub fn and_then<T, M: FnOnce(S) -> Outcome<T, E, F>>(self, f: M) -> Outcome<T, E, F> {
+        match self {
+            Success(val) => f(val),
+            Failure(val) => Failure(val),
+            Forward(val) => Forward(val),
+        }
+    }
+
     #[inline]
     fn formatting(&self) -> (Color, &'static str) {
         match *self {